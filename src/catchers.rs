@@ -0,0 +1,20 @@
+use rocket::Request;
+use rocket_contrib::templates::Template;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct ErrorContext {
+    uri: String,
+}
+
+#[catch(404)]
+pub fn not_found(req: &Request) -> Template {
+    let context = ErrorContext { uri: req.uri().path().to_string() };
+    Template::render("error/404", &context)
+}
+
+#[catch(500)]
+pub fn internal_error(req: &Request) -> Template {
+    let context = ErrorContext { uri: req.uri().path().to_string() };
+    Template::render("error/500", &context)
+}
@@ -0,0 +1,98 @@
+use std::io::Write;
+
+use handlebars::{Context, Handlebars, Helper, HelperResult, Output, RenderContext};
+use rocket_contrib::templates::Engines;
+
+/// Register every custom Handlebars helper used by the flying/commercial/ga/
+/// skiing templates. Passed to `Template::custom` so it runs once at
+/// startup, after Rocket's own `{{#if}}`/`{{#each}}` helpers are in place.
+pub fn register(engines: &mut Engines) {
+    engines.handlebars.register_helper("unit", Box::new(unit_helper));
+    engines.handlebars.register_helper("nav_active", Box::new(nav_active_helper));
+    engines.handlebars.register_helper("format_date", Box::new(format_date_helper));
+}
+
+/// `{{unit value from="kt" to="mph"}}` — convert an airspeed (or other
+/// aviation quantity) between units. No-ops when `value` is missing or
+/// isn't a number, or when the unit pair isn't recognized.
+fn unit_helper(h: &Helper, _: &Handlebars, _: &Context, _: &mut RenderContext, out: &mut dyn Output) -> HelperResult {
+    let value = match h.param(0).and_then(|v| v.value().as_f64()) {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    let from = h.hash_get("from").and_then(|v| v.value().as_str()).unwrap_or("");
+    let to = h.hash_get("to").and_then(|v| v.value().as_str()).unwrap_or("");
+
+    let converted = match (from, to) {
+        ("kt", "mph") => value * 1.15078,
+        ("mph", "kt") => value / 1.15078,
+        ("kt", "kph") => value * 1.852,
+        ("kph", "kt") => value / 1.852,
+        ("ft", "m") => value * 0.3048,
+        ("m", "ft") => value / 0.3048,
+        _ => return Ok(()),
+    };
+
+    write!(out, "{:.0}", converted)?;
+    Ok(())
+}
+
+/// `{{nav_active name}}` — emit `active` when `name` matches the route the
+/// current page was rendered for, so the nav bar can highlight it.
+fn nav_active_helper(h: &Helper, _: &Handlebars, ctx: &Context, _: &mut RenderContext, out: &mut dyn Output) -> HelperResult {
+    let name = match h.param(0).and_then(|v| v.value().as_str()) {
+        Some(name) => name,
+        None => return Ok(()),
+    };
+
+    let current = ctx
+        .data()
+        .get("route")
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+
+    if current == name {
+        write!(out, "active")?;
+    }
+    Ok(())
+}
+
+/// `{{format_date value}}` — render an ISO `YYYY-MM-DD` date as
+/// `Month D, YYYY`. No-ops when `value` is missing or malformed.
+fn format_date_helper(h: &Helper, _: &Handlebars, _: &Context, _: &mut RenderContext, out: &mut dyn Output) -> HelperResult {
+    let value = match h.param(0).and_then(|v| v.value().as_str()) {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+
+    let parts: Vec<&str> = value.split('-').collect();
+    if parts.len() != 3 {
+        return Ok(());
+    }
+    let (year, month, day) = (parts[0], parts[1], parts[2]);
+    if year.is_empty() || !year.chars().all(|c| c.is_ascii_digit()) {
+        return Ok(());
+    }
+    let day = match day.parse::<u32>() {
+        Ok(day) if (1..=31).contains(&day) => day,
+        _ => return Ok(()),
+    };
+    let month_name = match month {
+        "01" => "January",
+        "02" => "February",
+        "03" => "March",
+        "04" => "April",
+        "05" => "May",
+        "06" => "June",
+        "07" => "July",
+        "08" => "August",
+        "09" => "September",
+        "10" => "October",
+        "11" => "November",
+        "12" => "December",
+        _ => return Ok(()),
+    };
+
+    write!(out, "{} {}, {}", month_name, day, year)?;
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use rocket::request::{self, FromRequest, Request};
+use rocket::{Outcome, State};
+
+/// Locale used when a requested locale, or a key within it, isn't available.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Translation tables for every locale the site supports, loaded once at
+/// startup from a `locales/` directory of `<lang>.json` files.
+pub struct I18n {
+    tables: HashMap<String, HashMap<String, String>>,
+}
+
+impl I18n {
+    /// Load every `<lang>.json` file found directly under `dir`.
+    pub fn load(dir: impl AsRef<Path>) -> I18n {
+        let mut tables = HashMap::new();
+
+        if let Ok(entries) = fs::read_dir(dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                    continue;
+                }
+
+                let lang = match path.file_stem().and_then(|stem| stem.to_str()) {
+                    Some(lang) => lang.to_string(),
+                    None => continue,
+                };
+
+                if let Ok(contents) = fs::read_to_string(&path) {
+                    if let Ok(table) = serde_json::from_str(&contents) {
+                        tables.insert(lang, table);
+                    }
+                }
+            }
+        }
+
+        I18n { tables }
+    }
+
+    /// Look up a single translation key for `locale`, via the same
+    /// default-locale fallback as [`I18n::table`], falling back to `key`
+    /// itself when the key is absent from every table.
+    pub fn translate(&self, locale: &str, key: &str) -> String {
+        self.table(locale)
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    /// Whether `locale` has its own translation table.
+    pub fn has_locale(&self, locale: &str) -> bool {
+        self.tables.contains_key(locale)
+    }
+
+    /// The translation table for `locale`, seeded with [`DEFAULT_LOCALE`]'s
+    /// entries and overlaid with `locale`'s own, so a key missing from
+    /// `locale` still falls back to the default-locale translation instead
+    /// of disappearing from the rendered context.
+    pub fn table(&self, locale: &str) -> HashMap<String, String> {
+        let mut table = self.tables.get(DEFAULT_LOCALE).cloned().unwrap_or_default();
+        if let Some(overrides) = self.tables.get(locale) {
+            table.extend(overrides.clone());
+        }
+        table
+    }
+}
+
+/// The locale resolved for the current request: a `/<lang>/...` path prefix
+/// takes priority, then the `Accept-Language` header, then
+/// [`DEFAULT_LOCALE`].
+pub struct Locale(pub String);
+
+impl<'a, 'r> FromRequest<'a, 'r> for Locale {
+    type Error = ();
+
+    fn from_request(request: &'a Request<'r>) -> request::Outcome<Self, Self::Error> {
+        let i18n = match request.guard::<State<I18n>>() {
+            Outcome::Success(i18n) => i18n,
+            _ => return Outcome::Success(Locale(DEFAULT_LOCALE.to_string())),
+        };
+
+        if let Some(first_segment) = request.uri().segments().next() {
+            if i18n.has_locale(first_segment) {
+                return Outcome::Success(Locale(first_segment.to_string()));
+            }
+        }
+
+        if let Some(header) = request.headers().get_one("Accept-Language") {
+            for candidate in header.split(',') {
+                let lang = candidate.split(';').next().unwrap_or("").trim();
+                let lang = lang.split('-').next().unwrap_or("");
+                if i18n.has_locale(lang) {
+                    return Outcome::Success(Locale(lang.to_string()));
+                }
+            }
+        }
+
+        Outcome::Success(Locale(DEFAULT_LOCALE.to_string()))
+    }
+}
@@ -0,0 +1,48 @@
+#![feature(proc_macro_hygiene, decl_macro)]
+
+#[macro_use]
+extern crate rocket;
+
+mod catchers;
+mod helpers;
+mod i18n;
+mod routes;
+mod tips;
+
+use rocket_contrib::serve::{Options, StaticFiles};
+
+use i18n::I18n;
+use routes::flying::{book_fn, commercial_fn, commercial_fn_lang, flying_fn, flying_fn_lang, ga_fn, ga_fn_lang};
+use routes::skiing::{skiing_fn, skiing_fn_lang};
+
+fn main() {
+    let rocket = rocket::ignite();
+    let public_dir = rocket
+        .config()
+        .get_str("public_dir")
+        .unwrap_or("public")
+        .to_string();
+    let tips_corpus = rocket
+        .config()
+        .get_str("tips_corpus")
+        .unwrap_or("corpus/tips.txt")
+        .to_string();
+    tips::init(&tips_corpus);
+    let locales_dir = rocket
+        .config()
+        .get_str("locales_dir")
+        .unwrap_or("locales")
+        .to_string();
+
+    rocket
+        .manage(I18n::load(locales_dir))
+        .mount("/flying", routes![flying_fn, commercial_fn, ga_fn, book_fn])
+        .mount("/skiing", routes![skiing_fn])
+        .mount("/", routes![flying_fn_lang, commercial_fn_lang, ga_fn_lang, skiing_fn_lang])
+        .mount("/static", StaticFiles::new(public_dir, Options::None))
+        .register(catchers![catchers::not_found, catchers::internal_error])
+        .attach(rocket_contrib::templates::Template::custom(|engines| {
+            helpers::register(engines);
+        }))
+        .launch();
+}
@@ -1,24 +1,108 @@
-use rocket::*;
-use rocket_contrib::templates::Template;
 use std::collections::HashMap;
 
+use rocket::request::{FlashMessage, Form};
+use rocket::response::{Flash, Redirect};
+use rocket::State;
+use rocket_contrib::templates::Template;
+use serde::Serialize;
+
+use crate::i18n::{Locale, DEFAULT_LOCALE, I18n};
+use crate::tips;
+
+#[derive(Serialize)]
+struct PageContext {
+    t: HashMap<String, String>,
+    lang: String,
+    route: &'static str,
+    flash: Option<String>,
+    tip: Option<String>,
+}
+
+fn render(name: &'static str, route: &'static str, i18n: &I18n, lang: &str, flash: Option<String>) -> Template {
+    render_with_tip(name, route, i18n, lang, flash, None)
+}
+
+fn render_with_tip(
+    name: &'static str,
+    route: &'static str,
+    i18n: &I18n,
+    lang: &str,
+    flash: Option<String>,
+    tip: Option<String>,
+) -> Template {
+    let context = PageContext {
+        t: i18n.table(lang),
+        lang: lang.to_string(),
+        route,
+        flash,
+        tip,
+    };
+    Template::render(name, &context)
+}
+
 #[get("/")]
-pub fn flying_fn() -> Template {
-    let mut context = HashMap::new();
-    context.insert("context", "string");
-    Template::render("flying", &context)
+pub fn flying_fn(locale: Locale, i18n: State<I18n>) -> Template {
+    render_with_tip("flying", "flying", &i18n, &locale.0, None, Some(tips::tip_of_the_day()))
 }
 
 #[get("/commercial")]
-pub fn commercial_fn() -> Template {
-    let mut context = HashMap::new();
-    context.insert("context", "string");
-    Template::render("commercial", &context)
+pub fn commercial_fn(locale: Locale, i18n: State<I18n>) -> Template {
+    render("commercial", "commercial", &i18n, &locale.0, None)
 }
 
 #[get("/ga")]
-pub fn ga_fn() -> Template {
-    let mut context = HashMap::new();
-    context.insert("context", "string");
-    Template::render("ga", &context)
+pub fn ga_fn(locale: Locale, i18n: State<I18n>, flash: Option<FlashMessage>) -> Template {
+    let flash = flash.map(|flash| flash.msg().to_string());
+    render("ga", "ga", &i18n, &locale.0, flash)
+}
+
+/// Explicit-locale variants reachable via a `/<lang>/...` path prefix,
+/// mounted at the site root alongside the default-locale routes above. The
+/// `<_>` segment is ignored here and re-read from the raw URI by the
+/// `Locale` guard itself, which is what actually resolves it (falling back
+/// to the `Accept-Language` header, then the default locale).
+#[get("/<_>/flying")]
+pub fn flying_fn_lang(locale: Locale, i18n: State<I18n>) -> Template {
+    render_with_tip("flying", "flying", &i18n, &locale.0, None, Some(tips::tip_of_the_day()))
+}
+
+#[get("/<_>/commercial")]
+pub fn commercial_fn_lang(locale: Locale, i18n: State<I18n>) -> Template {
+    render("commercial", "commercial", &i18n, &locale.0, None)
+}
+
+#[get("/<_>/ga")]
+pub fn ga_fn_lang(locale: Locale, i18n: State<I18n>, flash: Option<FlashMessage>) -> Template {
+    let flash = flash.map(|flash| flash.msg().to_string());
+    render("ga", "ga", &i18n, &locale.0, flash)
+}
+
+/// A lesson-booking inquiry submitted from the GA page's contact form. The
+/// `lang` field carries the locale of the page the form was submitted
+/// from (a hidden input set from the `lang` template context value), since
+/// `/flying/book` itself has no `/<lang>/` prefix for the `Locale` guard to
+/// resolve against.
+#[derive(FromForm)]
+pub struct BookingForm {
+    name: String,
+    email: String,
+    message: String,
+    lang: String,
+}
+
+/// `POST /flying/book` — handles the lesson-booking form and redirects
+/// back to the GA page, in the locale the form was submitted from, with a
+/// flash message reporting the outcome.
+#[post("/book", data = "<booking>")]
+pub fn book_fn(booking: Form<BookingForm>, i18n: State<I18n>) -> Flash<Redirect> {
+    let lang = if i18n.has_locale(&booking.lang) { booking.lang.clone() } else { DEFAULT_LOCALE.to_string() };
+    let ga_uri = if lang == DEFAULT_LOCALE { "/flying/ga".to_string() } else { format!("/{}/ga", lang) };
+
+    if booking.name.trim().is_empty() || booking.email.trim().is_empty() {
+        let message = i18n.translate(&lang, "booking.error");
+        return Flash::error(Redirect::to(ga_uri), message);
+    }
+
+    let message = i18n.translate(&lang, "booking.success");
+    Flash::success(Redirect::to(ga_uri), message)
 }
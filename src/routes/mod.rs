@@ -0,0 +1,2 @@
+pub mod flying;
+pub mod skiing;
@@ -1,10 +1,39 @@
-use rocket::*;
-use rocket_contrib::templates::Template;
 use std::collections::HashMap;
 
+use rocket::State;
+use rocket_contrib::templates::Template;
+use serde::Serialize;
+
+use crate::i18n::{Locale, I18n};
+use crate::tips;
+
+#[derive(Serialize)]
+struct PageContext {
+    t: HashMap<String, String>,
+    lang: String,
+    route: &'static str,
+    tip: Option<String>,
+}
+
+fn render(name: &'static str, i18n: &I18n, lang: &str, tip: Option<String>) -> Template {
+    let context = PageContext {
+        t: i18n.table(lang),
+        lang: lang.to_string(),
+        route: "skiing",
+        tip,
+    };
+    Template::render(name, &context)
+}
+
 #[get("/")]
-pub fn skiing_fn() -> Template {
-    let mut context = HashMap::new();
-    context.insert("context", "string");
-    Template::render("skiing", &context)
+pub fn skiing_fn(locale: Locale, i18n: State<I18n>) -> Template {
+    render("skiing", &i18n, &locale.0, Some(tips::tip_of_the_day()))
+}
+
+/// Explicit-locale variant reachable via a `/<lang>/skiing` path prefix,
+/// mounted at the site root alongside `skiing_fn`. The `<_>` segment is
+/// ignored here and re-read from the raw URI by the `Locale` guard itself.
+#[get("/<_>/skiing")]
+pub fn skiing_fn_lang(locale: Locale, i18n: State<I18n>) -> Template {
+    render("skiing", &i18n, &locale.0, Some(tips::tip_of_the_day()))
 }
@@ -0,0 +1,50 @@
+use std::fs;
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+use markov::Chain;
+
+const FALLBACK_TIP: &str = "Clear skies and good turns ahead.";
+const DEFAULT_CORPUS_PATH: &str = "corpus/tips.txt";
+
+lazy_static! {
+    static ref TIP_CHAIN: Mutex<Chain<String>> = Mutex::new(build_chain(DEFAULT_CORPUS_PATH));
+}
+
+fn build_chain(path: &str) -> Chain<String> {
+    let mut chain = Chain::new();
+    if let Ok(corpus) = fs::read_to_string(path) {
+        for line in corpus.lines() {
+            let line = line.trim();
+            if !line.is_empty() {
+                chain.feed_str(line);
+            }
+        }
+    }
+    chain
+}
+
+/// Rebuild the tip corpus from `path`, overriding the default loaded at
+/// startup. Called once from `main` with the configured corpus path.
+pub fn init(path: &str) {
+    *TIP_CHAIN.lock().unwrap() = build_chain(path);
+}
+
+/// A short, randomly generated "tip of the day" for the flying and skiing
+/// landing pages. Falls back to [`FALLBACK_TIP`] when the corpus is empty,
+/// missing, or too short to generate a phrase from. `Chain::generate_str`
+/// panics on an untrained chain, so the empty check has to happen before
+/// calling it, not after.
+pub fn tip_of_the_day() -> String {
+    let chain = TIP_CHAIN.lock().unwrap();
+    if chain.is_empty() {
+        return FALLBACK_TIP.to_string();
+    }
+
+    let generated = chain.generate_str();
+    if generated.trim().is_empty() {
+        FALLBACK_TIP.to_string()
+    } else {
+        generated
+    }
+}